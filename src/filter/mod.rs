@@ -8,6 +8,7 @@ use libc::{c_char, c_int, c_uint, c_void};
 use crate::{
     codec::{
         audio::{AudioDecoder, AudioFrame},
+        video::{VideoDecoder, VideoFrame},
         Decoder,
         Frame,
     },
@@ -29,48 +30,46 @@ extern "C" {
     fn ffw_filter_free(name: *mut c_void);
 }
 
-/// A Filter Graph Builder
-pub struct FilterGraphBuilder {
+/// Media-agnostic filter graph plumbing shared by the audio and video
+/// filter graph builders: graph allocation, filter allocation and graph
+/// configuration don't care whether the frames flowing through are audio
+/// or video, so it lives here once instead of per media type.
+struct RawFilterGraphBuilder {
     ptr: *mut c_void,
     buffer_src: Option<Filter>,
     buffer_sink: Option<Filter>,
-    time_base: TimeBase,
     should_drop_graph: bool,
 }
 
-impl FilterGraphBuilder {
-    /// Create a new FilterGraphBuilder.
-    pub fn new(audio_decoder: &AudioDecoder) -> Result<Self, Error> {
+impl RawFilterGraphBuilder {
+    fn new() -> Result<Self, Error> {
         let ptr = unsafe { ffw_filter_graph_init() as *mut c_void };
 
         if ptr.is_null() {
             return Err(Error::new("out of memory"));
         }
 
-        let time_base = audio_decoder.time_base();
-
-        let res = FilterGraphBuilder {
+        let res = RawFilterGraphBuilder {
             ptr,
             buffer_src: None,
             buffer_sink: None,
-            time_base,
             should_drop_graph: true,
         };
-        
+
         Ok(res)
     }
 
-    pub fn set_buffer_src(&mut self, buffer_src: Filter) {
+    fn set_buffer_src(&mut self, buffer_src: Filter) {
         self.buffer_src = Some(buffer_src);
     }
 
-    pub fn set_buffer_sink(&mut self, buffer_sink: Filter) {
+    fn set_buffer_sink(&mut self, buffer_sink: Filter) {
         self.buffer_sink = Some(buffer_sink);
     }
 
     /// Create a new FilterBuilder for a `filter_type` filter.
     /// Remember to add the Filter when you build the FilterGraph.
-    pub fn create_filter(&mut self, filter_type: &str) -> Result<FilterBuilder, Error> {
+    fn create_filter(&mut self, filter_type: &str) -> Result<FilterBuilder, Error> {
         let filter_type = CString::new(filter_type).expect("invalid filter_type");
 
         let ptr = unsafe { ffw_filter_alloc(self.ptr, filter_type.as_ptr()) };
@@ -87,8 +86,8 @@ impl FilterGraphBuilder {
         Ok(res)
     }
 
-    /// Builds the FilterGraph with all filters created and links configured.
-    pub fn build(mut self, filters: Vec<Filter>) -> Result<FilterGraph, Error> {
+    /// Configures the graph with all filters created and links configured.
+    fn build(mut self, filters: Vec<Filter>) -> Result<RawFilterGraph, Error> {
         let ret = unsafe {
             ffw_filter_graph_config(self.ptr)
         };
@@ -98,12 +97,11 @@ impl FilterGraphBuilder {
         }
 
         self.should_drop_graph = false;
-        
-        let res = FilterGraph {
+
+        let res = RawFilterGraph {
             ptr: self.ptr,
             src: self.buffer_src.take().expect("No Buffer Source was set!"),
             sink: self.buffer_sink.take().expect("No Buffer Sink was set!"),
-            time_base: self.time_base,
             _filters: filters,
         };
 
@@ -111,7 +109,7 @@ impl FilterGraphBuilder {
     }
 }
 
-impl Drop for FilterGraphBuilder {
+impl Drop for RawFilterGraphBuilder {
     fn drop(&mut self) {
         if self.should_drop_graph {
             unsafe { ffw_filter_graph_free(self.ptr) }
@@ -119,61 +117,193 @@ impl Drop for FilterGraphBuilder {
     }
 }
 
-/// A Filter Graph
-pub struct FilterGraph {
+/// The configured counterpart of `RawFilterGraphBuilder`, holding the raw
+/// frame push/take machinery shared by `AudioFilterGraph`/`VideoFilterGraph`.
+struct RawFilterGraph {
     ptr: *mut c_void,
     src: Filter,
     sink: Filter,
-    time_base: TimeBase,
     _filters: Vec<Filter>,
 }
 
-impl FilterGraph {
-    pub fn builder(audio_decoder: &AudioDecoder) -> Result<FilterGraphBuilder, Error> {
-        FilterGraphBuilder::new(audio_decoder)
-    }
+impl RawFilterGraph {
+    /// Pushes a frame into the graph's buffer source. `frame` must be a
+    /// valid pointer to a frame of the media type the graph was built for.
+    unsafe fn push(&self, frame: *mut c_void) -> Result<(), Error> {
+        let ret = ffw_filter_push_frame(self.src.ptr, frame);
 
-    /// Take a frame to the FilterGraph
-    pub fn push(&self, frame: AudioFrame) -> Result<(), Error> {
-        unsafe {
-            let ret = ffw_filter_push_frame(self.src.ptr, frame.as_ptr());
-
-            if ret < 0 {
-                return Err(Error::from_raw_error_code(ret));
-            }
+        if ret < 0 {
+            return Err(Error::from_raw_error_code(ret));
         }
         Ok(())
     }
 
-    /// Take a frame from the FilterGraph. This should be called until `None` is returned.
-    pub fn take(&self) -> Result<Option<AudioFrame>, Error> {
+    /// Takes a frame from the graph's buffer sink. The returned pointer, if
+    /// any, is a valid frame of the media type the graph was built for and
+    /// is owned by the caller.
+    unsafe fn take(&self) -> Result<Option<*mut c_void>, Error> {
         let mut fptr = ptr::null_mut();
 
-        unsafe {
-            match ffw_filter_take_frame(self.sink.ptr, &mut fptr) {
-                1 => {
-                    if fptr.is_null() {
-                        panic!("no frame received")
-                    } else {
-                        Ok(Some(AudioFrame::from_raw_ptr(fptr, self.time_base)))
-                    }
-                },
-                0 => Ok(None),
-                e => Err(Error::from_raw_error_code(e))
-            }
+        match ffw_filter_take_frame(self.sink.ptr, &mut fptr) {
+            1 => {
+                if fptr.is_null() {
+                    panic!("no frame received")
+                } else {
+                    Ok(Some(fptr))
+                }
+            },
+            0 => Ok(None),
+            e => Err(Error::from_raw_error_code(e))
         }
     }
 }
 
-unsafe impl Send for FilterGraph {}
-unsafe impl Sync for FilterGraph {}
+unsafe impl Send for RawFilterGraph {}
+unsafe impl Sync for RawFilterGraph {}
 
-impl Drop for FilterGraph {
+impl Drop for RawFilterGraph {
     fn drop(&mut self) {
         unsafe { ffw_filter_graph_free(self.ptr) }
     }
 }
 
+/// A Filter Graph Builder for audio filter graphs.
+pub struct AudioFilterGraphBuilder {
+    raw: RawFilterGraphBuilder,
+    time_base: TimeBase,
+}
+
+impl AudioFilterGraphBuilder {
+    /// Create a new AudioFilterGraphBuilder.
+    pub fn new(audio_decoder: &AudioDecoder) -> Result<Self, Error> {
+        let res = AudioFilterGraphBuilder {
+            raw: RawFilterGraphBuilder::new()?,
+            time_base: audio_decoder.time_base(),
+        };
+
+        Ok(res)
+    }
+
+    pub fn set_buffer_src(&mut self, buffer_src: Filter) {
+        self.raw.set_buffer_src(buffer_src);
+    }
+
+    pub fn set_buffer_sink(&mut self, buffer_sink: Filter) {
+        self.raw.set_buffer_sink(buffer_sink);
+    }
+
+    /// Create a new FilterBuilder for a `filter_type` filter.
+    /// Remember to add the Filter when you build the FilterGraph.
+    pub fn create_filter(&mut self, filter_type: &str) -> Result<FilterBuilder, Error> {
+        self.raw.create_filter(filter_type)
+    }
+
+    /// Builds the AudioFilterGraph with all filters created and links configured.
+    pub fn build(self, filters: Vec<Filter>) -> Result<AudioFilterGraph, Error> {
+        let res = AudioFilterGraph {
+            raw: self.raw.build(filters)?,
+            time_base: self.time_base,
+        };
+
+        Ok(res)
+    }
+}
+
+/// An audio Filter Graph
+pub struct AudioFilterGraph {
+    raw: RawFilterGraph,
+    time_base: TimeBase,
+}
+
+impl AudioFilterGraph {
+    pub fn builder(audio_decoder: &AudioDecoder) -> Result<AudioFilterGraphBuilder, Error> {
+        AudioFilterGraphBuilder::new(audio_decoder)
+    }
+
+    /// Take a frame to the FilterGraph
+    pub fn push(&self, frame: AudioFrame) -> Result<(), Error> {
+        unsafe { self.raw.push(frame.as_ptr()) }
+    }
+
+    /// Take a frame from the FilterGraph. This should be called until `None` is returned.
+    pub fn take(&self) -> Result<Option<AudioFrame>, Error> {
+        let time_base = self.time_base;
+
+        Ok(unsafe { self.raw.take()? }.map(|fptr| AudioFrame::from_raw_ptr(fptr, time_base)))
+    }
+}
+
+/// Alias kept for source compatibility with the pre-video-support API.
+pub type FilterGraphBuilder = AudioFilterGraphBuilder;
+/// Alias kept for source compatibility with the pre-video-support API.
+pub type FilterGraph = AudioFilterGraph;
+
+/// A Filter Graph Builder for video filter graphs.
+pub struct VideoFilterGraphBuilder {
+    raw: RawFilterGraphBuilder,
+    time_base: TimeBase,
+}
+
+impl VideoFilterGraphBuilder {
+    /// Create a new VideoFilterGraphBuilder.
+    pub fn new(video_decoder: &VideoDecoder) -> Result<Self, Error> {
+        let res = VideoFilterGraphBuilder {
+            raw: RawFilterGraphBuilder::new()?,
+            time_base: video_decoder.time_base(),
+        };
+
+        Ok(res)
+    }
+
+    pub fn set_buffer_src(&mut self, buffer_src: Filter) {
+        self.raw.set_buffer_src(buffer_src);
+    }
+
+    pub fn set_buffer_sink(&mut self, buffer_sink: Filter) {
+        self.raw.set_buffer_sink(buffer_sink);
+    }
+
+    /// Create a new FilterBuilder for a `filter_type` filter.
+    /// Remember to add the Filter when you build the VideoFilterGraph.
+    pub fn create_filter(&mut self, filter_type: &str) -> Result<FilterBuilder, Error> {
+        self.raw.create_filter(filter_type)
+    }
+
+    /// Builds the VideoFilterGraph with all filters created and links configured.
+    pub fn build(self, filters: Vec<Filter>) -> Result<VideoFilterGraph, Error> {
+        let res = VideoFilterGraph {
+            raw: self.raw.build(filters)?,
+            time_base: self.time_base,
+        };
+
+        Ok(res)
+    }
+}
+
+/// A video Filter Graph
+pub struct VideoFilterGraph {
+    raw: RawFilterGraph,
+    time_base: TimeBase,
+}
+
+impl VideoFilterGraph {
+    pub fn builder(video_decoder: &VideoDecoder) -> Result<VideoFilterGraphBuilder, Error> {
+        VideoFilterGraphBuilder::new(video_decoder)
+    }
+
+    /// Take a frame to the VideoFilterGraph
+    pub fn push(&self, frame: VideoFrame) -> Result<(), Error> {
+        unsafe { self.raw.push(frame.as_ptr()) }
+    }
+
+    /// Take a frame from the VideoFilterGraph. This should be called until `None` is returned.
+    pub fn take(&self) -> Result<Option<VideoFrame>, Error> {
+        let time_base = self.time_base;
+
+        Ok(unsafe { self.raw.take()? }.map(|fptr| VideoFrame::from_raw_ptr(fptr, time_base)))
+    }
+}
+
 /// Builder for a filter.
 pub struct FilterBuilder {
     ptr: *mut c_void,